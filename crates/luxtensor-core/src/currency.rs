@@ -19,6 +19,200 @@ pub const LTS_PER_MMDT: u128 = 1_000_000_000_000_000_000_000_000; // 10^24 (1M M
 /// Number of decimal places for MDT
 pub const MDT_DECIMALS: u8 = 18;
 
+/// An error produced while parsing a decimal amount string.
+///
+/// The character-position variants carry the index (in `char`s, counted from
+/// the start of the input) of the offending character, so a wallet frontend can
+/// highlight exactly where the user's input went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseAmountError {
+    /// A character that is neither a digit nor the decimal point appeared at
+    /// `position`.
+    InvalidCharacter { c: char, position: usize },
+    /// The fractional part carries more digits than the denomination supports;
+    /// `position` points at the first digit beyond the allowed precision.
+    TooPrecise { position: usize },
+    /// The value does not fit in the `u128` LTS representation.
+    TooLarge,
+    /// The input contained no digits at all.
+    MissingDigits,
+    /// The overall structure was malformed (e.g. more than one decimal point).
+    InvalidFormat,
+}
+
+impl std::fmt::Display for ParseAmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseAmountError::InvalidCharacter { c, position } => {
+                write!(f, "invalid character '{}' at position {}", c, position)
+            }
+            ParseAmountError::TooPrecise { position } => {
+                write!(f, "too many decimal places, excess digit at position {}", position)
+            }
+            ParseAmountError::TooLarge => write!(f, "amount too large - would overflow"),
+            ParseAmountError::MissingDigits => write!(f, "no digits in amount"),
+            ParseAmountError::InvalidFormat => write!(f, "invalid amount format"),
+        }
+    }
+}
+
+impl std::error::Error for ParseAmountError {}
+
+/// Parse a decimal amount string into LTS against a denomination of the given
+/// precision (`decimals` fractional digits), scanning character by character so
+/// the first bad character or excess-precision digit is reported precisely.
+fn parse_decimal_to_lts(s: &str, decimals: usize) -> Result<u128, ParseAmountError> {
+    let mut dot: Option<usize> = None;
+    let mut digit_count = 0usize;
+    for (i, c) in s.chars().enumerate() {
+        if c == '.' {
+            if dot.is_some() {
+                return Err(ParseAmountError::InvalidFormat);
+            }
+            dot = Some(i);
+        } else if c.is_ascii_digit() {
+            digit_count += 1;
+        } else {
+            return Err(ParseAmountError::InvalidCharacter { c, position: i });
+        }
+    }
+    if digit_count == 0 {
+        return Err(ParseAmountError::MissingDigits);
+    }
+
+    // Every remaining character is an ASCII digit or a single '.', so byte and
+    // char indices coincide and slicing is safe.
+    let (whole_str, frac_str) = match dot {
+        Some(d) => (&s[..d], &s[d + 1..]),
+        None => (s, ""),
+    };
+
+    if frac_str.len() > decimals {
+        let position = dot.unwrap() + 1 + decimals;
+        return Err(ParseAmountError::TooPrecise { position });
+    }
+
+    let whole = if whole_str.is_empty() {
+        0
+    } else {
+        whole_str.parse::<u128>().map_err(|_| ParseAmountError::TooLarge)?
+    };
+
+    let mut frac = frac_str.to_string();
+    while frac.len() < decimals {
+        frac.push('0');
+    }
+    let fractional = if frac.is_empty() {
+        0
+    } else {
+        frac.parse::<u128>().map_err(|_| ParseAmountError::TooLarge)?
+    };
+
+    let scale = 10u128
+        .checked_pow(decimals as u32)
+        .ok_or(ParseAmountError::TooLarge)?;
+    // Reject an oversized whole part up front using the precomputed scale limit,
+    // before the multiply is attempted.
+    if whole > u128::MAX / scale {
+        return Err(ParseAmountError::TooLarge);
+    }
+    let whole_lts = whole * scale;
+    whole_lts
+        .checked_add(fractional)
+        .ok_or(ParseAmountError::TooLarge)
+}
+
+/// A currency denomination: a human-facing unit and how it relates to the base
+/// LTS unit.
+///
+/// Every variant carries its decimal offset (a power of ten) relative to LTS,
+/// so one unit of the denomination equals `10^offset` LTS. This mirrors the
+/// way Bitcoin's `Denomination` records an offset from satoshis, and lets the
+/// conversion layer treat MDT, its sub- and super-units uniformly instead of
+/// hard-coding each one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Denomination {
+    /// LTS, the base (smallest indivisible) unit — offset 0.
+    Lts,
+    /// MilliMDT, one-thousandth of an MDT — offset 15.
+    MilliMdt,
+    /// MDT, the main denomination — offset 18.
+    Mdt,
+    /// KiloMDT, one thousand MDT — offset 21.
+    KiloMdt,
+    /// MegaMDT, one million MDT — offset 24.
+    MegaMdt,
+}
+
+impl Denomination {
+    /// Number of LTS decimal places this denomination is offset from the base
+    /// unit, i.e. the count of fractional digits it supports.
+    pub const fn decimals(self) -> u8 {
+        match self {
+            Denomination::Lts => 0,
+            Denomination::MilliMdt => 15,
+            Denomination::Mdt => 18,
+            Denomination::KiloMdt => 21,
+            Denomination::MegaMdt => 24,
+        }
+    }
+
+    /// The scale factor `10^decimals`: the number of LTS in one unit of this
+    /// denomination.
+    pub const fn scale(self) -> u128 {
+        10u128.pow(self.decimals() as u32)
+    }
+
+    /// The largest whole-unit input of this denomination that can be scaled up
+    /// to LTS without overflowing `u128` (`u128::MAX / scale`).
+    ///
+    /// Precomputed so conversion and parsing can reject oversized inputs with a
+    /// single cheap comparison *before* attempting the multiply, rather than
+    /// discovering the overflow afterwards. This mirrors how `ethers` guards
+    /// `format_units` with fixed per-unit thresholds.
+    pub const fn max_whole_units(self) -> u128 {
+        u128::MAX / self.scale()
+    }
+
+    /// Convert a whole-unit count in this denomination to LTS, rejecting inputs
+    /// above [`max_whole_units`](Self::max_whole_units) up front with
+    /// [`ParseAmountError::TooLarge`].
+    pub fn units_to_lts(self, units: u128) -> Result<u128, ParseAmountError> {
+        if units > self.max_whole_units() {
+            return Err(ParseAmountError::TooLarge);
+        }
+        Ok(units * self.scale())
+    }
+
+    /// The textual suffix used when formatting and parsing amounts.
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Denomination::Lts => "LTS",
+            Denomination::MilliMdt => "mMDT",
+            Denomination::Mdt => "MDT",
+            Denomination::KiloMdt => "KMDT",
+            Denomination::MegaMdt => "MMDT",
+        }
+    }
+}
+
+impl std::str::FromStr for Denomination {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Matched case-sensitively so the milli prefix ("mMDT") stays distinct
+        // from the mega prefix ("MMDT").
+        match s {
+            "LTS" => Ok(Denomination::Lts),
+            "mMDT" => Ok(Denomination::MilliMdt),
+            "MDT" => Ok(Denomination::Mdt),
+            "KMDT" => Ok(Denomination::KiloMdt),
+            "MMDT" => Ok(Denomination::MegaMdt),
+            _ => Err(format!("Unknown denomination: {}", s)),
+        }
+    }
+}
+
 /// Convert MDT to LTS (smallest unit)
 /// Returns `None` if overflow would occur
 /// 
@@ -49,19 +243,19 @@ pub fn lts_to_mdt(lts: u128) -> u128 {
 }
 
 /// Format LTS amount as a human-readable MDT string with decimals
-/// 
+///
 /// # Example
 /// ```
 /// use luxtensor_core::currency::format_lts_as_mdt;
-/// 
+///
 /// let amount_lts = 1_500_000_000_000_000_000;
 /// let formatted = format_lts_as_mdt(amount_lts);
 /// assert_eq!(formatted, "1.500000000000000000 MDT");
 /// ```
 pub fn format_lts_as_mdt(lts: u128) -> String {
-    let mdt_whole = lts / LTS_PER_MDT;
-    let lts_fractional = lts % LTS_PER_MDT;
-    format!("{}.{:0width$} MDT", mdt_whole, lts_fractional, width = MDT_DECIMALS as usize)
+    // Thin wrapper over the display builder preserving the historical,
+    // full-precision output (no trailing-zero trimming).
+    Amount::from_lts(lts).display().trim_trailing_zeros(false).to_string()
 }
 
 /// Format LTS amount as raw LTS string
@@ -88,41 +282,451 @@ pub fn format_lts(lts: u128) -> String {
 /// let amount_lts = parse_mdt_to_lts("1.5").unwrap();
 /// assert_eq!(amount_lts, 1_500_000_000_000_000_000);
 /// ```
-pub fn parse_mdt_to_lts(mdt_str: &str) -> Result<u128, String> {
-    let parts: Vec<&str> = mdt_str.split('.').collect();
-    
-    match parts.len() {
-        1 => {
-            // Whole number only
-            let whole = parts[0].parse::<u128>()
-                .map_err(|_| "Invalid MDT amount".to_string())?;
-            mdt_to_lts(whole).ok_or_else(|| "Amount too large - would overflow".to_string())
+pub fn parse_mdt_to_lts(mdt_str: &str) -> Result<u128, ParseAmountError> {
+    parse_decimal_to_lts(mdt_str, MDT_DECIMALS as usize)
+}
+
+/// A signed LTS amount, used to express balance deltas — debits, credits, fee
+/// refunds, or the net change after a slash — that the unsigned `u128` path
+/// cannot represent.
+///
+/// The inner value is an `i128` count of LTS. Arithmetic mirrors the unsigned
+/// helpers with overflow-checked `checked_*` methods, and conversions to and
+/// from the unsigned LTS representation are range-checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedAmount(pub i128);
+
+impl SignedAmount {
+    /// The zero delta.
+    pub const ZERO: SignedAmount = SignedAmount(0);
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, rhs: SignedAmount) -> Option<SignedAmount> {
+        self.0.checked_add(rhs.0).map(SignedAmount)
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    pub fn checked_sub(self, rhs: SignedAmount) -> Option<SignedAmount> {
+        self.0.checked_sub(rhs.0).map(SignedAmount)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, rhs: SignedAmount) -> Option<SignedAmount> {
+        self.0.checked_mul(rhs.0).map(SignedAmount)
+    }
+
+    /// The absolute value. Returns `None` if it would overflow (i.e. `i128::MIN`).
+    pub fn abs(self) -> Option<SignedAmount> {
+        self.0.checked_abs().map(SignedAmount)
+    }
+
+    /// The sign: `-1`, `0`, or `1`.
+    pub fn signum(self) -> i128 {
+        self.0.signum()
+    }
+
+    /// Convert to an unsigned LTS amount. Returns `None` if negative.
+    pub fn to_unsigned(self) -> Option<u128> {
+        if self.0 < 0 {
+            None
+        } else {
+            Some(self.0 as u128)
+        }
+    }
+
+    /// Convert from an unsigned LTS amount. Returns `None` if it does not fit in
+    /// the signed range.
+    pub fn from_unsigned(lts: u128) -> Option<SignedAmount> {
+        if lts > i128::MAX as u128 {
+            None
+        } else {
+            Some(SignedAmount(lts as i128))
+        }
+    }
+}
+
+/// Format a signed LTS amount as a human-readable MDT string, printing a
+/// leading `-` for negative values.
+///
+/// # Example
+/// ```
+/// use luxtensor_core::currency::{format_signed_lts_as_mdt, SignedAmount};
+///
+/// let delta = SignedAmount(-1_500_000_000_000_000_000);
+/// assert_eq!(format_signed_lts_as_mdt(delta), "-1.500000000000000000 MDT");
+/// ```
+pub fn format_signed_lts_as_mdt(amount: SignedAmount) -> String {
+    let magnitude = amount.0.unsigned_abs();
+    if amount.0 < 0 {
+        format!("-{}", format_lts_as_mdt(magnitude))
+    } else {
+        format_lts_as_mdt(magnitude)
+    }
+}
+
+/// Rounding mode for proportional amount math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward zero (plain integer division).
+    Down,
+    /// Round away from zero (ceiling).
+    Up,
+    /// Round to the nearest integer, ties rounded up.
+    NearestHalfUp,
+}
+
+/// Full-width `u128 * u128` multiply, returning the 256-bit product as
+/// `(high, low)` 128-bit limbs.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_lo, a_hi) = (a & MASK, a >> 64);
+    let (b_lo, b_hi) = (b & MASK, b >> 64);
+
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+
+    let cross = (ll >> 64) + (lh & MASK) + (hl & MASK);
+    let lo = (cross << 64) | (ll & MASK);
+    let hi = hh + (lh >> 64) + (hl >> 64) + (cross >> 64);
+    (hi, lo)
+}
+
+/// Divide a 256-bit value (`hi:lo` limbs) by a 128-bit divisor, returning the
+/// quotient if it fits in `u128`, otherwise `None`. `None` is also returned for
+/// a zero divisor.
+fn div_256_by_128(hi: u128, lo: u128, d: u128) -> Option<u128> {
+    if d == 0 {
+        return None;
+    }
+    let mut rem: u128 = 0;
+    let mut quot: u128 = 0;
+    let mut overflow = false;
+    for i in (0..256).rev() {
+        let bit = if i >= 128 {
+            (hi >> (i - 128)) & 1
+        } else {
+            (lo >> i) & 1
+        };
+        // Conceptually shift `rem` left by one and bring in the next bit; track
+        // the carried-out 129th bit separately so nothing exceeds u128.
+        let rem_top = rem >> 127;
+        let shifted = (rem << 1) | bit;
+        if rem_top == 1 || shifted >= d {
+            rem = shifted.wrapping_sub(d);
+            if i >= 128 {
+                overflow = true;
+            } else {
+                quot |= 1u128 << i;
+            }
+        } else {
+            rem = shifted;
+        }
+    }
+    if overflow {
+        None
+    } else {
+        Some(quot)
+    }
+}
+
+/// Compute `amount * numerator / denominator` with an explicit rounding mode,
+/// without losing precision to intermediate truncation.
+///
+/// The product is evaluated at full 256-bit width, so it never overflows; the
+/// rounding offset (`denominator - 1` for [`Rounding::Up`], `denominator / 2`
+/// for [`Rounding::NearestHalfUp`], none for [`Rounding::Down`]) is added before
+/// the divide. Returns `None` if the denominator is zero or the quotient does
+/// not fit in `u128`.
+///
+/// # Example
+/// ```
+/// use luxtensor_core::currency::{checked_mul_ratio, Rounding};
+///
+/// // 2.5% of 1000 = 25
+/// assert_eq!(checked_mul_ratio(1000, 25, 1000, Rounding::Down), Some(25));
+/// // 1/3 of 10, rounded up
+/// assert_eq!(checked_mul_ratio(10, 1, 3, Rounding::Up), Some(4));
+/// ```
+pub fn checked_mul_ratio(
+    amount: u128,
+    numerator: u128,
+    denominator: u128,
+    rounding: Rounding,
+) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let (hi, lo) = widening_mul(amount, numerator);
+    let addend = match rounding {
+        Rounding::Down => 0,
+        Rounding::Up => denominator - 1,
+        Rounding::NearestHalfUp => denominator / 2,
+    };
+    let (lo, carry) = lo.overflowing_add(addend);
+    let hi = hi.checked_add(carry as u128)?;
+    div_256_by_128(hi, lo, denominator)
+}
+
+/// An LTS amount, the entry point to the denomination-aware formatting layer.
+///
+/// Wraps a raw `u128` count of LTS and hands out an [`AmountDisplay`] builder
+/// for rendering it in any denomination with configurable trimming and suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(u128);
+
+impl Amount {
+    /// Wrap a raw LTS value.
+    pub const fn from_lts(lts: u128) -> Amount {
+        Amount(lts)
+    }
+
+    /// The underlying LTS value.
+    pub const fn as_lts(self) -> u128 {
+        self.0
+    }
+
+    /// Begin configuring how this amount is displayed.
+    ///
+    /// Defaults to MDT, trailing fractional zeros trimmed, suffix shown.
+    pub const fn display(self) -> AmountDisplay {
+        AmountDisplay {
+            lts: self.0,
+            denom: Denomination::Mdt,
+            trim: true,
+            suffix: true,
         }
-        2 => {
-            // Whole and fractional parts
-            let whole = parts[0].parse::<u128>()
-                .map_err(|_| "Invalid MDT amount".to_string())?;
-            
-            // Pad fractional part to MDT_DECIMALS digits
-            let mut frac_str = parts[1].to_string();
-            if frac_str.len() > MDT_DECIMALS as usize {
-                return Err(format!("Too many decimal places (max {})", MDT_DECIMALS));
+    }
+}
+
+/// A configurable, [`Display`](std::fmt::Display)-able view over an [`Amount`].
+///
+/// Built via [`Amount::display`]. The formatter's fill, width, and alignment
+/// (`{:>12}`) are honoured, so the rendered amount can be padded like any other
+/// value.
+#[derive(Debug, Clone, Copy)]
+pub struct AmountDisplay {
+    lts: u128,
+    denom: Denomination,
+    trim: bool,
+    suffix: bool,
+}
+
+impl AmountDisplay {
+    /// Render in the given denomination.
+    pub fn denomination(mut self, denom: Denomination) -> Self {
+        self.denom = denom;
+        self
+    }
+
+    /// Whether to strip trailing fractional zeros (and a bare trailing point).
+    /// Enabled by default.
+    pub fn trim_trailing_zeros(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Whether to append the denomination suffix. Enabled by default.
+    pub fn with_suffix(mut self, suffix: bool) -> Self {
+        self.suffix = suffix;
+        self
+    }
+}
+
+impl std::fmt::Display for AmountDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimals = self.denom.decimals() as usize;
+        let mut s = if decimals == 0 {
+            self.lts.to_string()
+        } else {
+            let scale = self.denom.scale();
+            let whole = self.lts / scale;
+            let fractional = self.lts % scale;
+            // Zero-pad the fractional part to the denomination's precision.
+            let mut frac = format!("{:0width$}", fractional, width = decimals);
+            if self.trim {
+                while frac.ends_with('0') {
+                    frac.pop();
+                }
             }
-            while frac_str.len() < MDT_DECIMALS as usize {
-                frac_str.push('0');
+            if frac.is_empty() {
+                // Minimal representation: "1.000…" collapses to "1".
+                whole.to_string()
+            } else {
+                format!("{}.{}", whole, frac)
             }
-            
-            let fractional = frac_str.parse::<u128>()
-                .map_err(|_| "Invalid fractional amount".to_string())?;
-            
-            let whole_lts = mdt_to_lts(whole)
-                .ok_or_else(|| "Amount too large - would overflow".to_string())?;
-            
-            whole_lts.checked_add(fractional)
-                .ok_or_else(|| "Amount too large - would overflow".to_string())
+        };
+        if self.suffix {
+            s.push(' ');
+            s.push_str(self.denom.suffix());
         }
-        _ => Err("Invalid MDT format".to_string()),
+        // Honour fill/width/alignment from the outer formatter.
+        f.pad(&s)
+    }
+}
+
+/// A fixed-point decimal backed by a `u128` with an implicit `10^18` scale, so
+/// the stored integer `1_000_000_000_000_000_000` represents `1.0`.
+///
+/// Because one MDT is `10^18` LTS, the raw value is exactly an LTS count — the
+/// type reuses the balance representation while adding scale-aware arithmetic
+/// (`checked_mul`/`checked_div` carry the `10^18` factor through a 256-bit
+/// intermediate) for on-chain math such as APR accrual or weighted averages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MdtDecimal(u128);
+
+impl MdtDecimal {
+    /// Zero.
+    pub const ZERO: MdtDecimal = MdtDecimal(0);
+    /// One (`10^18` in the raw representation).
+    pub const ONE: MdtDecimal = MdtDecimal(LTS_PER_MDT);
+
+    /// Build from a raw LTS count (which is already the `10^18`-scaled value).
+    pub const fn from_lts(lts: u128) -> MdtDecimal {
+        MdtDecimal(lts)
+    }
+
+    /// The raw LTS count.
+    pub const fn to_lts(self) -> u128 {
+        self.0
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, rhs: MdtDecimal) -> Option<MdtDecimal> {
+        self.0.checked_add(rhs.0).map(MdtDecimal)
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    pub fn checked_sub(self, rhs: MdtDecimal) -> Option<MdtDecimal> {
+        self.0.checked_sub(rhs.0).map(MdtDecimal)
+    }
+
+    /// Checked multiplication: `(a * b) / 10^18`, evaluated at 256-bit width.
+    /// Returns `None` if the result does not fit in `u128`.
+    pub fn checked_mul(self, rhs: MdtDecimal) -> Option<MdtDecimal> {
+        let (hi, lo) = widening_mul(self.0, rhs.0);
+        div_256_by_128(hi, lo, LTS_PER_MDT).map(MdtDecimal)
+    }
+
+    /// Checked division: `(a * 10^18) / b`, evaluated at 256-bit width. Returns
+    /// `None` on divide-by-zero or if the result does not fit in `u128`.
+    pub fn checked_div(self, rhs: MdtDecimal) -> Option<MdtDecimal> {
+        let (hi, lo) = widening_mul(self.0, LTS_PER_MDT);
+        div_256_by_128(hi, lo, rhs.0).map(MdtDecimal)
+    }
+
+    /// Saturating addition.
+    pub fn saturating_add(self, rhs: MdtDecimal) -> MdtDecimal {
+        MdtDecimal(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction (clamped at zero).
+    pub fn saturating_sub(self, rhs: MdtDecimal) -> MdtDecimal {
+        MdtDecimal(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Saturating multiplication (clamped at [`u128::MAX`] on overflow).
+    pub fn saturating_mul(self, rhs: MdtDecimal) -> MdtDecimal {
+        self.checked_mul(rhs).unwrap_or(MdtDecimal(u128::MAX))
+    }
+}
+
+impl std::str::FromStr for MdtDecimal {
+    type Err = ParseAmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_decimal_to_lts(s, MDT_DECIMALS as usize).map(MdtDecimal)
+    }
+}
+
+impl std::fmt::Display for MdtDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let whole = self.0 / LTS_PER_MDT;
+        let fractional = self.0 % LTS_PER_MDT;
+        write!(f, "{}.{:0width$}", whole, fractional, width = MDT_DECIMALS as usize)
+    }
+}
+
+/// Parse a decimal amount expressed in the given denomination into LTS.
+///
+/// Accepts the same `"1"`, `"1.5"`, `"0.5"` forms as [`parse_mdt_to_lts`], but
+/// interprets the value against `denom`'s scale. For example `"1.5"` with
+/// [`Denomination::KiloMdt`] is 1500 MDT worth of LTS.
+///
+/// # Example
+/// ```
+/// use luxtensor_core::currency::{parse_with_denomination, Denomination};
+///
+/// let lts = parse_with_denomination("1.5", Denomination::Mdt).unwrap();
+/// assert_eq!(lts, 1_500_000_000_000_000_000);
+/// ```
+pub fn parse_with_denomination(s: &str, denom: Denomination) -> Result<u128, ParseAmountError> {
+    parse_decimal_to_lts(s, denom.decimals() as usize)
+}
+
+/// Format an LTS amount as a human-readable string in the given denomination,
+/// including the unit suffix.
+///
+/// # Example
+/// ```
+/// use luxtensor_core::currency::{format_with_denomination, Denomination};
+///
+/// let s = format_with_denomination(1_500_000_000_000_000_000, Denomination::Mdt);
+/// assert_eq!(s, "1.500000000000000000 MDT");
+/// ```
+pub fn format_with_denomination(lts: u128, denom: Denomination) -> String {
+    let decimals = denom.decimals() as usize;
+    if decimals == 0 {
+        return format!("{} {}", lts, denom.suffix());
+    }
+    let scale = denom.scale();
+    let whole = lts / scale;
+    let fractional = lts % scale;
+    format!("{}.{:0width$} {}", whole, fractional, denom.suffix(), width = decimals)
+}
+
+/// Parse a user-entered amount carrying a trailing denomination suffix, such as
+/// `"1.5 MDT"`, `"1500 KMDT"`, or `"250000 LTS"`, into LTS.
+///
+/// The numeric part and the suffix may be separated by whitespace or written
+/// adjacently (`"1.5MDT"`). The suffix is matched case-sensitively so the milli
+/// unit (`mMDT`) stays distinct from the mega unit (`MMDT`).
+///
+/// # Example
+/// ```
+/// use luxtensor_core::currency::parse_amount;
+///
+/// assert_eq!(parse_amount("1.5 MDT").unwrap(), 1_500_000_000_000_000_000);
+/// assert_eq!(parse_amount("1500 KMDT").unwrap(), 1_500_000_000_000_000_000_000_000);
+/// ```
+pub fn parse_amount(s: &str) -> Result<u128, ParseAmountError> {
+    // Leading whitespace is trimmed below; error positions are shifted back by
+    // this many chars so they still index into the caller's input.
+    let offset = s.chars().count() - s.trim_start().chars().count();
+    let s = s.trim();
+    // Split off the trailing alphabetic suffix; what's left is the number.
+    let split_at = s
+        .find(|c: char| c.is_ascii_alphabetic())
+        .ok_or(ParseAmountError::InvalidFormat)?;
+    let (num, suffix) = s.split_at(split_at);
+    let num = num.trim();
+    if num.is_empty() {
+        return Err(ParseAmountError::MissingDigits);
     }
+    let denom: Denomination = suffix
+        .trim()
+        .parse()
+        .map_err(|_| ParseAmountError::InvalidFormat)?;
+    parse_with_denomination(num, denom).map_err(|e| match e {
+        ParseAmountError::InvalidCharacter { c, position } => {
+            ParseAmountError::InvalidCharacter { c, position: position + offset }
+        }
+        ParseAmountError::TooPrecise { position } => {
+            ParseAmountError::TooPrecise { position: position + offset }
+        }
+        other => other,
+    })
 }
 
 #[cfg(test)]
@@ -193,6 +797,260 @@ mod tests {
         assert!(parse_mdt_to_lts(large_value).is_err());
     }
 
+    #[test]
+    fn test_denomination_scale_limits() {
+        assert_eq!(Denomination::Lts.max_whole_units(), u128::MAX);
+        assert_eq!(Denomination::Mdt.max_whole_units(), u128::MAX / LTS_PER_MDT);
+        assert_eq!(Denomination::MegaMdt.max_whole_units(), u128::MAX / LTS_PER_MMDT);
+    }
+
+    #[test]
+    fn test_units_to_lts_guard() {
+        assert_eq!(Denomination::Mdt.units_to_lts(1), Ok(LTS_PER_MDT));
+        let max = Denomination::MegaMdt.max_whole_units();
+        assert!(Denomination::MegaMdt.units_to_lts(max).is_ok());
+        assert_eq!(
+            Denomination::MegaMdt.units_to_lts(max + 1),
+            Err(ParseAmountError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_oversized_high_unit() {
+        let over = (Denomination::MegaMdt.max_whole_units() + 1).to_string();
+        assert_eq!(
+            parse_with_denomination(&over, Denomination::MegaMdt),
+            Err(ParseAmountError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_mdt_decimal_arithmetic() {
+        let two = MdtDecimal::from_lts(2 * LTS_PER_MDT);
+        let three = MdtDecimal::from_lts(3 * LTS_PER_MDT);
+        assert_eq!(two.checked_add(three), Some(MdtDecimal::from_lts(5 * LTS_PER_MDT)));
+        assert_eq!(three.checked_sub(two), Some(MdtDecimal::ONE));
+        // 2 * 3 = 6
+        assert_eq!(two.checked_mul(three), Some(MdtDecimal::from_lts(6 * LTS_PER_MDT)));
+        // 3 / 2 = 1.5
+        assert_eq!(
+            three.checked_div(two),
+            Some(MdtDecimal::from_lts(1_500_000_000_000_000_000))
+        );
+        assert_eq!(two.checked_div(MdtDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_mdt_decimal_saturating() {
+        let max = MdtDecimal::from_lts(u128::MAX);
+        assert_eq!(max.saturating_add(MdtDecimal::ONE), max);
+        assert_eq!(MdtDecimal::ZERO.saturating_sub(MdtDecimal::ONE), MdtDecimal::ZERO);
+        assert_eq!(max.saturating_mul(max), max);
+    }
+
+    #[test]
+    fn test_mdt_decimal_parse_display() {
+        use std::str::FromStr;
+        let d = MdtDecimal::from_str("1.5").unwrap();
+        assert_eq!(d, MdtDecimal::from_lts(1_500_000_000_000_000_000));
+        assert_eq!(d.to_string(), "1.500000000000000000");
+        assert!(MdtDecimal::from_str("1.2").is_ok());
+        assert_eq!(
+            MdtDecimal::from_str("x"),
+            Err(ParseAmountError::InvalidCharacter { c: 'x', position: 0 })
+        );
+    }
+
+    #[test]
+    fn test_parse_amount_error_positions() {
+        assert_eq!(
+            parse_mdt_to_lts("1x5"),
+            Err(ParseAmountError::InvalidCharacter { c: 'x', position: 1 })
+        );
+        assert_eq!(
+            parse_mdt_to_lts("1.1234567890123456789"),
+            Err(ParseAmountError::TooPrecise { position: 20 })
+        );
+        assert_eq!(parse_mdt_to_lts("1.1.1"), Err(ParseAmountError::InvalidFormat));
+        assert_eq!(parse_mdt_to_lts(""), Err(ParseAmountError::MissingDigits));
+        assert_eq!(parse_mdt_to_lts("."), Err(ParseAmountError::MissingDigits));
+        assert_eq!(
+            parse_mdt_to_lts("340282366920938463464"),
+            Err(ParseAmountError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_parse_amount_error_display() {
+        let err = ParseAmountError::InvalidCharacter { c: 'x', position: 1 };
+        assert_eq!(err.to_string(), "invalid character 'x' at position 1");
+        // Exercise the std::error::Error impl.
+        let _: &dyn std::error::Error = &err;
+    }
+
+    #[test]
+    fn test_checked_mul_ratio_basic() {
+        assert_eq!(checked_mul_ratio(1000, 25, 1000, Rounding::Down), Some(25));
+        assert_eq!(checked_mul_ratio(1000, 1, 3, Rounding::Down), Some(333));
+        assert_eq!(checked_mul_ratio(1000, 1, 3, Rounding::Up), Some(334));
+        assert_eq!(checked_mul_ratio(10, 1, 3, Rounding::Up), Some(4));
+    }
+
+    #[test]
+    fn test_checked_mul_ratio_rounding_modes() {
+        // 10 * 1 / 4 = 2.5 -> NearestHalfUp rounds to 3, Down to 2, Up to 3
+        assert_eq!(checked_mul_ratio(10, 1, 4, Rounding::Down), Some(2));
+        assert_eq!(checked_mul_ratio(10, 1, 4, Rounding::NearestHalfUp), Some(3));
+        assert_eq!(checked_mul_ratio(10, 1, 4, Rounding::Up), Some(3));
+        // exact division is unaffected by rounding
+        assert_eq!(checked_mul_ratio(12, 1, 4, Rounding::NearestHalfUp), Some(3));
+    }
+
+    #[test]
+    fn test_checked_mul_ratio_overflow_and_zero() {
+        assert_eq!(checked_mul_ratio(1, 1, 0, Rounding::Down), None);
+        // No overflow even when the product exceeds u128.
+        assert_eq!(
+            checked_mul_ratio(u128::MAX, 2, 2, Rounding::Down),
+            Some(u128::MAX)
+        );
+        // Quotient itself exceeds u128 -> None.
+        assert_eq!(checked_mul_ratio(u128::MAX, 2, 1, Rounding::Down), None);
+    }
+
+    #[test]
+    fn test_amount_display_trims_by_default() {
+        assert_eq!(
+            Amount::from_lts(1_500_000_000_000_000_000).display().to_string(),
+            "1.5 MDT"
+        );
+        // "1.000…" collapses to "1".
+        assert_eq!(
+            Amount::from_lts(1_000_000_000_000_000_000).display().to_string(),
+            "1 MDT"
+        );
+    }
+
+    #[test]
+    fn test_amount_display_options() {
+        let a = Amount::from_lts(1_500_000_000_000_000_000);
+        assert_eq!(a.display().with_suffix(false).to_string(), "1.5");
+        assert_eq!(
+            a.display().denomination(Denomination::KiloMdt).to_string(),
+            "0.0015 KMDT"
+        );
+        assert_eq!(
+            a.display().trim_trailing_zeros(false).to_string(),
+            "1.500000000000000000 MDT"
+        );
+    }
+
+    #[test]
+    fn test_amount_display_respects_width_alignment() {
+        let a = Amount::from_lts(1_500_000_000_000_000_000);
+        assert_eq!(format!("{:>12}", a.display()), "     1.5 MDT");
+    }
+
+    #[test]
+    fn test_format_lts_as_mdt_wrapper_unchanged() {
+        assert_eq!(
+            format_lts_as_mdt(1_500_000_000_000_000_000),
+            "1.500000000000000000 MDT"
+        );
+    }
+
+    #[test]
+    fn test_signed_amount_arithmetic() {
+        let a = SignedAmount(5);
+        let b = SignedAmount(-3);
+        assert_eq!(a.checked_add(b), Some(SignedAmount(2)));
+        assert_eq!(a.checked_sub(b), Some(SignedAmount(8)));
+        assert_eq!(a.checked_mul(b), Some(SignedAmount(-15)));
+        assert_eq!(b.abs(), Some(SignedAmount(3)));
+        assert_eq!(b.signum(), -1);
+        assert_eq!(SignedAmount::ZERO.signum(), 0);
+        assert_eq!(SignedAmount(i128::MAX).checked_add(SignedAmount(1)), None);
+    }
+
+    #[test]
+    fn test_signed_amount_conversions() {
+        assert_eq!(SignedAmount(5).to_unsigned(), Some(5));
+        assert_eq!(SignedAmount(-5).to_unsigned(), None);
+        assert_eq!(SignedAmount::from_unsigned(5), Some(SignedAmount(5)));
+        assert_eq!(SignedAmount::from_unsigned(u128::MAX), None);
+    }
+
+    #[test]
+    fn test_format_signed_lts_as_mdt() {
+        assert_eq!(
+            format_signed_lts_as_mdt(SignedAmount(1_500_000_000_000_000_000)),
+            "1.500000000000000000 MDT"
+        );
+        assert_eq!(
+            format_signed_lts_as_mdt(SignedAmount(-1_500_000_000_000_000_000)),
+            "-1.500000000000000000 MDT"
+        );
+    }
+
+    #[test]
+    fn test_denomination_scale() {
+        assert_eq!(Denomination::Lts.scale(), 1);
+        assert_eq!(Denomination::MilliMdt.scale(), 1_000_000_000_000_000);
+        assert_eq!(Denomination::Mdt.scale(), LTS_PER_MDT);
+        assert_eq!(Denomination::KiloMdt.scale(), LTS_PER_KMDT);
+        assert_eq!(Denomination::MegaMdt.scale(), LTS_PER_MMDT);
+    }
+
+    #[test]
+    fn test_parse_with_denomination() {
+        assert_eq!(
+            parse_with_denomination("1.5", Denomination::Mdt).unwrap(),
+            1_500_000_000_000_000_000
+        );
+        assert_eq!(
+            parse_with_denomination("1500", Denomination::KiloMdt).unwrap(),
+            1_500_000_000_000_000_000_000_000
+        );
+        assert_eq!(
+            parse_with_denomination("42", Denomination::Lts).unwrap(),
+            42
+        );
+        assert!(parse_with_denomination("1.5", Denomination::Lts).is_err()); // no fraction in base unit
+    }
+
+    #[test]
+    fn test_format_with_denomination() {
+        assert_eq!(
+            format_with_denomination(1_500_000_000_000_000_000, Denomination::Mdt),
+            "1.500000000000000000 MDT"
+        );
+        assert_eq!(
+            format_with_denomination(42, Denomination::Lts),
+            "42 LTS"
+        );
+    }
+
+    #[test]
+    fn test_parse_amount_with_suffix() {
+        assert_eq!(parse_amount("1.5 MDT").unwrap(), 1_500_000_000_000_000_000);
+        assert_eq!(parse_amount("1.5MDT").unwrap(), 1_500_000_000_000_000_000);
+        assert_eq!(
+            parse_amount("1500 KMDT").unwrap(),
+            1_500_000_000_000_000_000_000_000
+        );
+        assert_eq!(parse_amount("250000 LTS").unwrap(), 250_000);
+        assert!(parse_amount("1.5").is_err()); // missing suffix
+        assert!(parse_amount("1.5 FOO").is_err()); // unknown suffix
+    }
+
+    #[test]
+    fn test_denomination_from_str() {
+        use std::str::FromStr;
+        assert_eq!(Denomination::from_str("MDT").unwrap(), Denomination::Mdt);
+        assert_eq!(Denomination::from_str("mMDT").unwrap(), Denomination::MilliMdt);
+        assert_eq!(Denomination::from_str("MMDT").unwrap(), Denomination::MegaMdt);
+    }
+
     #[test]
     fn test_constants() {
         assert_eq!(LTS_PER_MDT, 1_000_000_000_000_000_000);